@@ -23,15 +23,22 @@ use std::env;
 #[derive(Debug)]
 pub enum Argument {
     /// A positional argument - the most standard type, e.g. `myprogram file.txt` has the positional argument 'file.txt'.
+    /// Everything after a bare `--` token is also positional, even if it starts with '-'.
     /// Takes a `String` - the name of the positional argument.
     Positional(String),
     /// A flag - given after a single '-' symbol, can be grouped together. e.g. `myprogram -fo file.txt` has the flags 'f' and 'o'. Seperated for clarity in the Argument enum.
+    /// `parse_arguments()`/`parse_arguments_lenient()` always group short chars this way; `Parser::parse()` instead
+    /// turns a short char into an `Argument::Variable` when its `ArgSpec` was registered with `takes_value(true)`
+    /// and a value is attached or follows as a token not starting with '-'; otherwise it stays a `Flag`.
     /// Takes a `char`: the character as the flag.
     Flag(char),
     /// An option - expanded out version of a flag given after two '-' symbols, and does not include an '=' sign. e.g. `myprogram file.txt --quiet` has the option 'quiet'.
     /// Takes a `String`: the name of the option.
     Option(String),
-    /// A variable: flag and a value given by an '=' sign. e.g. `myprogram file.txt --output-type=quiet` has the variable '--output-type' set to 'quiet'.
+    /// A variable: a name and a value. Produced by a `--name=value` token, or, when `Parser::parse()`
+    /// knows a short char takes a value, by that short flag consuming its attached or following token -
+    /// `myprogram -o file.o` and `myprogram -ofile.o` both produce a variable named `"o"` with value
+    /// `"file.o"`, matching how getopts-style parsers let a short option consume its argument.
     /// Takes a `name: String` and a `value: String` - The name and value of the variable.
     Variable { name: String, value: String },
 }
@@ -39,17 +46,17 @@ pub enum Argument {
 /// ParsedArguments (`struct`): Ordered arguments structure for developer access.
 /// Provides a higher level access to arguments of a program, including the arguments as a `Vec<Argument>`, the positionals, flags, options, and variables.
 /// Returned by the `parse_arguments()` function.
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// let testing_arguments: Vec<String> = String::from("file.txt -o file.o --quiet --on-warnings=exit")  // Example arguments
 ///     .split_whitespace()                                                                             // ...
 ///     .map(|s| s.to_string())                                                                         // ...
 ///     .collect();                                                                                     // ...
-/// let parsed_arguments: ParsedArguments = parse_arguments();                                          // Parse the arguments, returning a ParsedArguments struct.
+/// let parsed_arguments: ParsedArguments = parse_arguments(testing_arguments).unwrap();                // Parse the arguments, returning a ParsedArguments struct.
 /// println!(parsed_arguments.positionals)                                                              // Prints `["file.txt", "file.o"]`
-/// println!(parsed_arguments.flags)                                                                    // Prints `[-o']`
+/// println!(parsed_arguments.flags)                                                                    // Prints `['o']`
 /// println!(parsed_arguments.options)                                                                  // Prints `["quiet"]`
 /// println!(parsed_arguments.variables)                                                                // Prints `[{ "on-warnings": "exit"}]`
 /// ```
@@ -87,29 +94,202 @@ impl CheckableIfArgument for String {
     }
 }
 
-pub fn parse_arguments(args: Vec<String>) -> ParsedArguments {
+impl ParsedArguments {
+    /// Returns the full, ordered sequence of `Argument`s exactly as the tokenizer produced them.
+    pub fn arguments(&self) -> &[Argument] {
+        return &self.arguments;
+    }
+
+    /// Returns whether the given flag character was present in the parsed arguments.
+    pub fn has_flag(&self, c: char) -> bool {
+        return self.flags.contains(&c);
+    }
+
+    /// Returns whether the given option name was present in the parsed arguments.
+    pub fn has_option(&self, name: &str) -> bool {
+        return self.options.iter().any(|option| option == name);
+    }
+
+    /// Returns the positional argument at `index`, if one was provided.
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        return self.positionals.get(index).map(|s| s.as_str());
+    }
+
+    /// Returns the value of the variable named `name`, if one was provided.
+    pub fn variable(&self, name: &str) -> Option<&str> {
+        return self.variables.get(name).map(|s| s.as_str());
+    }
+
+    /// Returns the variable named `name`, parsed as `T`.
+    /// Returns `None` only when the variable is absent - a present-but-invalid value
+    /// is surfaced as `Some(Err(_))` so callers can tell the two cases apart.
+    pub fn variable_as<T: std::str::FromStr>(&self, name: &str) -> Option<Result<T, T::Err>> {
+        return self.variable(name).map(|value| value.parse::<T>());
+    }
+
+    /// Returns the positional argument at `index`, parsed as `T`.
+    /// Returns `None` only when `index` is out of bounds - a present-but-invalid value
+    /// is surfaced as `Some(Err(_))` so callers can tell the two cases apart.
+    pub fn positional_as<T: std::str::FromStr>(&self, index: usize) -> Option<Result<T, T::Err>> {
+        return self.positional(index).map(|value| value.parse::<T>());
+    }
+}
+
+/// ParseError (`Enum`): The ways that a line of arguments can fail to tokenize or validate.
+/// Returned by `parse_arguments()` when the input cannot be interpreted - see
+/// `parse_arguments_lenient()` for a best-effort alternative that never fails - and by
+/// `Parser::parse()`/`SubcommandParser::parse()` when it does not match their registrations.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A `--name=value` style token had an empty name, e.g. `--=value`.
+    /// Carries the original offending token.
+    MalformedVariable(String),
+    /// A `-` token was given with no flag characters following it.
+    /// Carries the original offending token.
+    EmptyFlagGroup(String),
+    /// An option or variable name was not one the caller declared as valid.
+    /// Carries the offending option/variable name. Not produced by
+    /// `parse_arguments()` itself - reserved for the `Parser`/`ArgSpec` layer.
+    UnknownOption(String),
+    /// A required option/variable was not present anywhere in the input.
+    /// Carries the missing option/variable name. Reserved for the
+    /// `Parser`/`ArgSpec` layer.
+    MissingRequiredOption(String),
+    /// No subcommand name was given where one was required. Reserved for the
+    /// `SubcommandParser` layer.
+    MissingSubcommand,
+    /// The token given as the subcommand name (the first token, or the one after `help`)
+    /// didn't match any registered subcommand.
+    /// Carries the offending token. Reserved for the `SubcommandParser` layer.
+    UnknownSubcommand(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MalformedVariable(token) => {
+                write!(f, "malformed variable: '{}'", token)
+            }
+            ParseError::EmptyFlagGroup(token) => {
+                write!(f, "empty flag group: '{}'", token)
+            }
+            ParseError::UnknownOption(name) => {
+                write!(f, "unknown option: '{}'", name)
+            }
+            ParseError::MissingRequiredOption(name) => {
+                write!(f, "missing required option: '{}'", name)
+            }
+            ParseError::MissingSubcommand => {
+                write!(f, "missing subcommand")
+            }
+            ParseError::UnknownSubcommand(name) => {
+                write!(f, "unknown subcommand: '{}'", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Tokenizes `args` into a `ParsedArguments`, stopping with a `ParseError` on the
+/// first malformed token. Short flags are always grouped as plain booleans here - e.g.
+/// `-rf` yields the flags 'r' and 'f' - since this function has no way to know which
+/// short chars are meant to take a value. See `Parser::parse()` for short options that
+/// take a value, and `parse_arguments_lenient()` to skip bad tokens instead of erroring.
+pub fn parse_arguments(args: Vec<String>) -> Result<ParsedArguments, ParseError> {
+    return parse_arguments_impl(args, true, &|_| false);
+}
+
+/// Tokenizes `args` into a `ParsedArguments`, silently skipping any token it can't
+/// make sense of. See `parse_arguments()` for a variant that reports the failure and
+/// for a note on short-flag grouping.
+pub fn parse_arguments_lenient(args: Vec<String>) -> ParsedArguments {
+    return parse_arguments_impl(args, false, &|_| false).expect("lenient parsing never fails");
+}
+
+/// Tokenizes `args`, consulting `takes_value` to decide whether a given short flag
+/// character is allowed to consume a value. This is the shared implementation behind
+/// `parse_arguments()`/`parse_arguments_lenient()` (which pass a predicate that always
+/// returns `false`, preserving plain grouped boolean flags) and `Parser::parse()` (which
+/// passes a predicate backed by the registered `ArgSpec`s).
+fn parse_arguments_impl(
+    args: Vec<String>,
+    strict: bool,
+    takes_value: &dyn Fn(char) -> bool,
+) -> Result<ParsedArguments, ParseError> {
     let mut arguments: Vec<Argument> = Vec::new();
     let mut positionals: Vec<String> = Vec::new();
     let mut options: Vec<String> = Vec::new();
     let mut flags: Vec<char> = Vec::new();
     let mut variables: HashMap<String, String> = HashMap::new();
 
-    for item in args {
+    let mut tokens = args.into_iter().peekable();
+    let mut past_delimiter = false;
+
+    while let Some(item) = tokens.next() {
+        if past_delimiter {
+            // Everything after a bare `--` is positional, even if it looks like an option.
+            positionals.push(item.clone());
+            arguments.push(Argument::Positional(item));
+            continue;
+        }
+
+        if item == "--" {
+            past_delimiter = true;
+            continue;
+        }
+
         if item.is_positional() {
             // Check for a positional argument
             positionals.push(item.clone());
-            arguments.push(Argument::Positional(item.clone()));
+            arguments.push(Argument::Positional(item));
         }
 
         else if item.is_flag() {
-            // Check for flags
+            // Check for a short flag group, e.g. `-fo` - each char is its own boolean flag
+            // unless `takes_value` says it expects a value, in which case the rest of the
+            // token (or, if nothing is attached, the following token) is consumed as that
+            // flag's value and the group ends there.
             let original = item.clone();
             let trimmed = original[1..].to_string();
-            flags.append(&mut trimmed.clone().chars().collect());
-            for flag in trimmed.chars().into_iter() {
-                arguments.push(Argument::Flag(flag));
+            if trimmed.is_empty() {
+                if strict {
+                    return Err(ParseError::EmptyFlagGroup(item));
+                }
+                continue;
             }
-        } 
+
+            let chars: Vec<char> = trimmed.chars().collect();
+            let mut index = 0;
+            while index < chars.len() {
+                let flag = chars[index];
+
+                if !takes_value(flag) {
+                    flags.push(flag);
+                    arguments.push(Argument::Flag(flag));
+                    index += 1;
+                    continue;
+                }
+
+                let attached: String = chars[index + 1..].iter().collect();
+                if !attached.is_empty() {
+                    // Attached form, e.g. `-ofile.o` - everything after the flag char is its value.
+                    flags.push(flag);
+                    variables.insert(flag.to_string(), attached.clone());
+                    arguments.push(Argument::Variable { name: flag.to_string(), value: attached });
+                } else if tokens.peek().is_some_and(|next| !next.starts_with('-')) {
+                    // Separate-token form, e.g. `-o file.o` - the following token is its value.
+                    let value = tokens.next().unwrap();
+                    flags.push(flag);
+                    variables.insert(flag.to_string(), value.clone());
+                    arguments.push(Argument::Variable { name: flag.to_string(), value });
+                } else {
+                    flags.push(flag);
+                    arguments.push(Argument::Flag(flag));
+                }
+                break;
+            }
+        }
 
         else if item.is_option() {
             // Check for a non-variable option
@@ -117,7 +297,7 @@ pub fn parse_arguments(args: Vec<String>) -> ParsedArguments {
             let trimmed = original[2..].to_string();
             options.push(trimmed.clone());
             arguments.push(Argument::Option(trimmed));
-        } 
+        }
 
         else if item.is_variable() {
             // Check for a variable
@@ -125,26 +305,30 @@ pub fn parse_arguments(args: Vec<String>) -> ParsedArguments {
             let trimmed = original[2..].to_string();
             let split = trimmed.split_once('=');
             match split {
-                Some(value) => {
-                    let (before, after) = value;
+                Some((before, after)) if !before.is_empty() => {
                     variables.insert(before.to_string(), after.to_string());
                     arguments.push(Argument::Variable {
                         name: before.to_string(),
                         value: after.to_string(),
                     })
                 }
-                None => continue,
+                _ => {
+                    if strict {
+                        return Err(ParseError::MalformedVariable(item));
+                    }
+                    continue;
+                }
             }
         }
     }
 
-    return ParsedArguments {
+    return Ok(ParsedArguments {
         arguments,
         positionals,
         flags,
         options,
         variables,
-    };
+    });
 }
 
 pub fn collect_args() -> Vec<String> {
@@ -155,6 +339,511 @@ pub fn get_raw_args_string() -> String {
     return collect_args().join(" ");
 }
 
-pub fn collect_args_and_parse() -> ParsedArguments {
+pub fn collect_args_and_parse() -> Result<ParsedArguments, ParseError> {
     return parse_arguments(collect_args());
+}
+
+/// ArgSpec (`struct`): Describes a single option/flag a program expects, for registration
+/// on a `Parser`. Built with a chained, `self`-consuming builder API.
+///
+/// # Examples
+///
+/// ```
+/// use simple_arg_parser::*;
+///
+/// let threads = ArgSpec::new("threads")
+///     .short('t')
+///     .takes_value(true)
+///     .default("1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArgSpec {
+    long: String,
+    short: Option<char>,
+    takes_value: bool,
+    required: bool,
+    default: Option<String>,
+    help: Option<String>,
+    env: Option<String>,
+}
+
+impl ArgSpec {
+    /// Starts a new spec for the long option name `long` (without leading dashes).
+    pub fn new(long: &str) -> Self {
+        return ArgSpec {
+            long: long.to_string(),
+            short: None,
+            takes_value: false,
+            required: false,
+            default: None,
+            help: None,
+            env: None,
+        };
+    }
+
+    /// Gives this option a single-character short form, e.g. `-o` alongside `--output`.
+    pub fn short(mut self, c: char) -> Self {
+        self.short = Some(c);
+        return self;
+    }
+
+    /// Marks whether this option takes a value (a variable) rather than being a bare flag.
+    pub fn takes_value(mut self, takes_value: bool) -> Self {
+        self.takes_value = takes_value;
+        return self;
+    }
+
+    /// Marks whether `Parser::parse` should error if this option is missing entirely.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        return self;
+    }
+
+    /// Sets the value used when this option takes a value but was not provided.
+    pub fn default(mut self, default: &str) -> Self {
+        self.default = Some(default.to_string());
+        return self;
+    }
+
+    /// Sets the description shown for this option in `--help` output.
+    pub fn help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        return self;
+    }
+
+    /// Lets this option's value fall back to the named environment variable when it is
+    /// not present on the command line. Precedence is command line > environment > default.
+    pub fn env(mut self, name: &str) -> Self {
+        self.env = Some(name.to_string());
+        return self;
+    }
+}
+
+/// OptionSource (`Enum`): Where a `takes_value` option's resolved value ultimately came
+/// from, so a caller can tell an explicit flag apart from an environment
+/// fallback or a declared default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionSource {
+    /// The value was given directly on the command line (or via its short-flag alias).
+    CommandLine,
+    /// The value was pulled from the environment variable set with `ArgSpec::env`.
+    Environment,
+    /// Neither the command line nor the environment supplied a value; the `ArgSpec::default` was used.
+    Default,
+}
+
+/// ParseOutcome (`Enum`): What came out of a `Parser::parse` call - either a successfully
+/// validated `ParsedArguments`, or a request to print help and exit without running anything.
+#[derive(Debug)]
+pub enum ParseOutcome {
+    /// The input matched the registered specs; carries the validated arguments alongside
+    /// where each `takes_value` option's value was ultimately resolved from.
+    Matched {
+        args: ParsedArguments,
+        sources: HashMap<String, OptionSource>,
+    },
+    /// `--help`/`-h` was present; carries the formatted help text to print.
+    HelpRequested(String),
+}
+
+/// Parser (`struct`): A registry of `ArgSpec`s that validates and fills in a `ParsedArguments`
+/// on top of the raw tokenizer, the way a program describes its actual interface. Also carries
+/// the program name/version used to render `--help` output.
+///
+/// # Examples
+///
+/// ```
+/// use simple_arg_parser::*;
+///
+/// let parser = Parser::new()
+///     .name("myprog")
+///     .version("1.0")
+///     .arg(ArgSpec::new("output").short('o').takes_value(true).required(true).help("Output file path"));
+/// match parser.parse(vec![String::from("--output=out.txt")]).unwrap() {
+///     ParseOutcome::Matched { args, sources } => { /* ... */ }
+///     ParseOutcome::HelpRequested(text) => println!("{}", text),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Parser {
+    specs: Vec<ArgSpec>,
+    program: String,
+    version: Option<String>,
+}
+
+/// Column the help description starts in, matching the option column's fixed width.
+const HELP_OPTION_COLUMN_WIDTH: usize = 24;
+/// Total line width help descriptions are wrapped to.
+const HELP_WRAP_WIDTH: usize = 79;
+
+impl Parser {
+    /// Creates an empty `Parser` with no registered specs and no program name.
+    pub fn new() -> Self {
+        return Parser {
+            specs: Vec::new(),
+            program: String::new(),
+            version: None,
+        };
+    }
+
+    /// Registers an `ArgSpec`, returning `self` so registrations can be chained.
+    pub fn arg(mut self, spec: ArgSpec) -> Self {
+        self.specs.push(spec);
+        return self;
+    }
+
+    /// Sets the program name shown in `--help` output.
+    pub fn name(mut self, program: &str) -> Self {
+        self.program = program.to_string();
+        return self;
+    }
+
+    /// Sets the version shown in `--help` output.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        return self;
+    }
+
+    /// Tokenizes `args` and validates the result against the registered specs.
+    /// If `--help`/`-h` is present, short-circuits with `ParseOutcome::HelpRequested`
+    /// before any validation runs, so help can always be requested. Otherwise,
+    /// unrecognized options/variables and missing required options are rejected,
+    /// and declared defaults are filled in for options that take a value but were
+    /// not supplied. Short flags are accepted as aliases of their long-name spec:
+    /// a short char registered with `takes_value(true)` consumes an attached or
+    /// following token as a value (e.g. `-o file.o`), folded into the long name's
+    /// slot; any other short char is still groupable with its neighbours as a plain
+    /// boolean flag, e.g. `-rf` where only `f` takes a value.
+    pub fn parse(&self, args: Vec<String>) -> Result<ParseOutcome, ParseError> {
+        let mut parsed = parse_arguments_impl(args, true, &|c| {
+            self.specs.iter().any(|spec| spec.takes_value && spec.short == Some(c))
+        })?;
+
+        if parsed.has_option("help") || parsed.has_flag('h') {
+            return Ok(ParseOutcome::HelpRequested(self.format_help()));
+        }
+
+        for option in &parsed.options {
+            if !self.specs.iter().any(|spec| &spec.long == option) {
+                return Err(ParseError::UnknownOption(option.clone()));
+            }
+        }
+        for name in parsed.variables.keys() {
+            let is_known = self.specs.iter().any(|spec| {
+                &spec.long == name || spec.short.is_some_and(|c| c.to_string() == *name)
+            });
+            if !is_known {
+                return Err(ParseError::UnknownOption(name.clone()));
+            }
+        }
+
+        let mut sources: HashMap<String, OptionSource> = HashMap::new();
+
+        for spec in &self.specs {
+            if spec.takes_value {
+                if parsed.variables.contains_key(&spec.long) {
+                    sources.insert(spec.long.clone(), OptionSource::CommandLine);
+                    continue;
+                }
+                let short_value = spec
+                    .short
+                    .and_then(|c| parsed.variables.get(&c.to_string()).cloned());
+                if let Some(value) = short_value {
+                    parsed.variables.insert(spec.long.clone(), value);
+                    sources.insert(spec.long.clone(), OptionSource::CommandLine);
+                } else if let Some(value) = spec.env.as_ref().and_then(|name| env::var(name).ok()) {
+                    parsed.variables.insert(spec.long.clone(), value);
+                    sources.insert(spec.long.clone(), OptionSource::Environment);
+                } else if let Some(default) = &spec.default {
+                    parsed.variables.insert(spec.long.clone(), default.clone());
+                    sources.insert(spec.long.clone(), OptionSource::Default);
+                } else if spec.required {
+                    return Err(ParseError::MissingRequiredOption(spec.long.clone()));
+                }
+            } else {
+                let present = parsed.has_option(&spec.long)
+                    || spec.short.is_some_and(|c| parsed.has_flag(c));
+                if spec.required && !present {
+                    return Err(ParseError::MissingRequiredOption(spec.long.clone()));
+                }
+            }
+        }
+
+        return Ok(ParseOutcome::Matched {
+            args: parsed,
+            sources,
+        });
+    }
+
+    /// Prints the formatted help/usage text to stdout.
+    pub fn print_help(&self) {
+        print!("{}", self.format_help());
+    }
+
+    /// Builds the `--help` output: a `USAGE:` line synthesized from the required vs
+    /// optional specs, followed by a two-column listing of every registered option,
+    /// padded to `HELP_OPTION_COLUMN_WIDTH` with descriptions wrapped to
+    /// `HELP_WRAP_WIDTH` columns.
+    pub fn format_help(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(version) = &self.version {
+            out.push_str(&format!("{} {}\n\n", self.program, version));
+        } else if !self.program.is_empty() {
+            out.push_str(&format!("{}\n\n", self.program));
+        }
+
+        out.push_str("USAGE:\n    ");
+        out.push_str(&self.program);
+        out.push_str(" [OPTIONS]");
+        for spec in &self.specs {
+            if spec.required {
+                out.push_str(&format!(
+                    " --{}{}",
+                    spec.long,
+                    if spec.takes_value { " <VALUE>" } else { "" }
+                ));
+            }
+        }
+        out.push_str("\n\nOPTIONS:\n");
+
+        for spec in &self.specs {
+            out.push_str(&format_help_line(spec));
+        }
+
+        return out;
+    }
+}
+
+/// Renders a single `OPTIONS:` row for `spec`, matching the column layout documented
+/// on `Parser::format_help`.
+fn format_help_line(spec: &ArgSpec) -> String {
+    let mut left = String::from("    ");
+    if let Some(short) = spec.short {
+        left.push('-');
+        left.push(short);
+        left.push_str(", ");
+    }
+    left.push_str("--");
+    left.push_str(&spec.long);
+    if spec.takes_value {
+        left.push_str(" <VALUE>");
+    }
+
+    let wrap_width = HELP_WRAP_WIDTH.saturating_sub(HELP_OPTION_COLUMN_WIDTH);
+    let mut help_text = spec.help.clone().unwrap_or_default();
+    if let Some(env_name) = &spec.env {
+        if !help_text.is_empty() {
+            help_text.push(' ');
+        }
+        help_text.push_str(&format!("[env: {}]", env_name));
+    }
+    let wrapped = wrap_text(&help_text, wrap_width);
+
+    let mut line = String::new();
+    if left.len() < HELP_OPTION_COLUMN_WIDTH {
+        line.push_str(&format!("{:<width$}", left, width = HELP_OPTION_COLUMN_WIDTH));
+    } else {
+        line.push_str(&left);
+        line.push('\n');
+        line.push_str(&" ".repeat(HELP_OPTION_COLUMN_WIDTH));
+    }
+    line.push_str(&wrapped[0]);
+    line.push('\n');
+    for extra in &wrapped[1..] {
+        line.push_str(&" ".repeat(HELP_OPTION_COLUMN_WIDTH));
+        line.push_str(extra);
+        line.push('\n');
+    }
+
+    return line;
+}
+
+/// Greedily wraps `text` on word boundaries so no line exceeds `width` columns; a single
+/// word longer than `width` is not split and sits on its own, longer line.
+/// Always returns at least one (possibly empty) line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        if current.is_empty() {
+            current.push_str(word);
+        } else if current.len() + 1 + word.len() <= width {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            lines.push(current.clone());
+            current = word.to_string();
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+
+    return lines;
+}
+
+/// Subcommand (`struct`): A named subcommand owning its own `Parser`, for registration
+/// on a `SubcommandParser`. e.g. `myprog commit --amend` routes into the `commit` subcommand.
+#[derive(Debug, Clone)]
+pub struct Subcommand {
+    name: String,
+    parser: Parser,
+}
+
+impl Subcommand {
+    /// Registers a subcommand named `name` that parses its own arguments with `parser`.
+    pub fn new(name: &str, parser: Parser) -> Self {
+        return Subcommand {
+            name: name.to_string(),
+            parser,
+        };
+    }
+}
+
+/// SubcommandOutcome (`Enum`): What came out of a `SubcommandParser::parse` call - either
+/// a selected subcommand with its own validated arguments, or a request to print help text.
+#[derive(Debug)]
+pub enum SubcommandOutcome {
+    /// A subcommand was selected; carries its name, its own validated arguments, and
+    /// where each of its `takes_value` options was ultimately resolved from.
+    Matched {
+        name: String,
+        args: ParsedArguments,
+        sources: HashMap<String, OptionSource>,
+    },
+    /// Help was requested, either for the top level or for a specific subcommand.
+    HelpRequested(String),
+}
+
+/// SubcommandParser (`struct`): Dispatches the first token of the input to one of a set of
+/// registered `Subcommand`s, the way git-style CLIs route `commit`, `push`, etc. to their
+/// own argument handling. Options placed before the subcommand name are not skipped: a
+/// leading token such as `--verbose` is itself taken as the subcommand name.
+///
+/// # Examples
+///
+/// ```
+/// use simple_arg_parser::*;
+///
+/// let dispatcher = SubcommandParser::new()
+///     .name("myprog")
+///     .subcommand(Subcommand::new("commit", Parser::new()
+///         .arg(ArgSpec::new("amend"))
+///         .arg(ArgSpec::new("message").short('m').takes_value(true))));
+/// match dispatcher.parse(vec![String::from("commit"), String::from("--amend")]).unwrap() {
+///     SubcommandOutcome::Matched { name, args, sources } => { /* ... */ }
+///     SubcommandOutcome::HelpRequested(text) => println!("{}", text),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SubcommandParser {
+    program: String,
+    version: Option<String>,
+    subcommands: Vec<Subcommand>,
+}
+
+impl SubcommandParser {
+    /// Creates an empty `SubcommandParser` with no registered subcommands.
+    pub fn new() -> Self {
+        return SubcommandParser {
+            program: String::new(),
+            version: None,
+            subcommands: Vec::new(),
+        };
+    }
+
+    /// Registers a `Subcommand`, returning `self` so registrations can be chained.
+    pub fn subcommand(mut self, subcommand: Subcommand) -> Self {
+        self.subcommands.push(subcommand);
+        return self;
+    }
+
+    /// Sets the program name shown in help output.
+    pub fn name(mut self, program: &str) -> Self {
+        self.program = program.to_string();
+        return self;
+    }
+
+    /// Sets the version shown in help output.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        return self;
+    }
+
+    /// Matches the first token of `args` against the registered subcommands and parses
+    /// the remaining tokens in that subcommand's own context. `myprog help <sub>` and
+    /// `myprog <sub> --help` both short-circuit with that subcommand's own help text;
+    /// a bare `myprog --help`/`-h`/`help` short-circuits with the top-level help text.
+    pub fn parse(&self, args: Vec<String>) -> Result<SubcommandOutcome, ParseError> {
+        let mut tokens = args.into_iter();
+        let first = tokens.next();
+
+        match first {
+            None => return Err(ParseError::MissingSubcommand),
+            Some(token) if token == "--help" || token == "-h" => {
+                return Ok(SubcommandOutcome::HelpRequested(self.format_help()));
+            }
+            Some(token) if token == "help" => {
+                return match tokens.next() {
+                    Some(name) => self
+                        .find(&name)
+                        .map(|sub| SubcommandOutcome::HelpRequested(self.qualified_parser(sub).format_help()))
+                        .ok_or(ParseError::UnknownSubcommand(name)),
+                    None => Ok(SubcommandOutcome::HelpRequested(self.format_help())),
+                };
+            }
+            Some(token) => {
+                let subcommand = self
+                    .find(&token)
+                    .ok_or_else(|| ParseError::UnknownSubcommand(token.clone()))?;
+                let rest: Vec<String> = tokens.collect();
+                return match self.qualified_parser(subcommand).parse(rest)? {
+                    ParseOutcome::Matched { args, sources } => Ok(SubcommandOutcome::Matched {
+                        name: subcommand.name.clone(),
+                        args,
+                        sources,
+                    }),
+                    ParseOutcome::HelpRequested(text) => Ok(SubcommandOutcome::HelpRequested(text)),
+                };
+            }
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&Subcommand> {
+        return self.subcommands.iter().find(|sub| sub.name == name);
+    }
+
+    /// Clones `sub`'s `Parser`, renaming it to `"<dispatcher program> <sub name>"` so its
+    /// own `--help` output is identifiable instead of showing a blank program field.
+    fn qualified_parser(&self, sub: &Subcommand) -> Parser {
+        return sub.parser.clone().name(&format!("{} {}", self.program, sub.name));
+    }
+
+    /// Prints the formatted top-level help text to stdout.
+    pub fn print_help(&self) {
+        print!("{}", self.format_help());
+    }
+
+    /// Builds the top-level `--help` output: a `USAGE:` line plus a listing of every
+    /// registered subcommand name.
+    pub fn format_help(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(version) = &self.version {
+            out.push_str(&format!("{} {}\n\n", self.program, version));
+        } else if !self.program.is_empty() {
+            out.push_str(&format!("{}\n\n", self.program));
+        }
+
+        out.push_str("USAGE:\n    ");
+        out.push_str(&self.program);
+        out.push_str(" <SUBCOMMAND> [OPTIONS]\n\nSUBCOMMANDS:\n");
+        for subcommand in &self.subcommands {
+            out.push_str(&format!("    {}\n", subcommand.name));
+        }
+
+        return out;
+    }
 }
\ No newline at end of file
@@ -6,6 +6,264 @@ fn test_argument_parser_get_struct() {
         .split_whitespace()
         .map(|s| s.to_string())
         .collect();
-    let test = parse_arguments(args);
+    let test = parse_arguments(args).unwrap();
     dbg!(test);
 }
+
+#[test]
+fn test_variable_as_some_ok_when_present_and_valid() {
+    let args = vec![String::from("--count=4")];
+    let parsed = parse_arguments(args).unwrap();
+    assert_eq!(parsed.variable_as::<u32>("count"), Some(Ok(4)));
+}
+
+#[test]
+fn test_variable_as_some_err_when_present_and_invalid() {
+    let args = vec![String::from("--count=not-a-number")];
+    let parsed = parse_arguments(args).unwrap();
+    assert!(parsed.variable_as::<u32>("count").unwrap().is_err());
+}
+
+#[test]
+fn test_variable_as_none_when_absent() {
+    let parsed = parse_arguments(vec![]).unwrap();
+    assert_eq!(parsed.variable_as::<u32>("count"), None);
+}
+
+#[test]
+fn test_positional_as_some_ok_when_present_and_valid() {
+    let args = vec![String::from("4")];
+    let parsed = parse_arguments(args).unwrap();
+    assert_eq!(parsed.positional_as::<u32>(0), Some(Ok(4)));
+}
+
+#[test]
+fn test_positional_as_some_err_when_present_and_invalid() {
+    let args = vec![String::from("not-a-number")];
+    let parsed = parse_arguments(args).unwrap();
+    assert!(parsed.positional_as::<u32>(0).unwrap().is_err());
+}
+
+#[test]
+fn test_positional_as_none_when_out_of_bounds() {
+    let parsed = parse_arguments(vec![]).unwrap();
+    assert_eq!(parsed.positional_as::<u32>(0), None);
+}
+
+#[test]
+fn test_format_help_renders_usage_and_option_columns() {
+    let parser = Parser::new()
+        .name("myprog")
+        .version("1.0")
+        .arg(
+            ArgSpec::new("output")
+                .short('o')
+                .takes_value(true)
+                .required(true)
+                .env("OUTPUT_PATH")
+                .help("Path to write the generated output file to, overwriting any existing file"),
+        )
+        .arg(ArgSpec::new("verbose").short('v').help("Print extra diagnostic information"));
+
+    let expected = "\
+myprog 1.0
+
+USAGE:
+    myprog [OPTIONS] --output <VALUE>
+
+OPTIONS:
+    -o, --output <VALUE>
+                        Path to write the generated output file to, overwriting
+                        any existing file [env: OUTPUT_PATH]
+    -v, --verbose       Print extra diagnostic information
+";
+    assert_eq!(parser.format_help(), expected);
+}
+
+#[test]
+fn test_parse_arguments_strict_errors_on_malformed_variable() {
+    let args = vec![String::from("--=value")];
+    let err = parse_arguments(args).unwrap_err();
+    assert_eq!(err, ParseError::MalformedVariable(String::from("--=value")));
+}
+
+#[test]
+fn test_parse_arguments_strict_errors_on_empty_flag_group() {
+    let args = vec![String::from("-")];
+    let err = parse_arguments(args).unwrap_err();
+    assert_eq!(err, ParseError::EmptyFlagGroup(String::from("-")));
+}
+
+#[test]
+fn test_parse_arguments_lenient_skips_bad_tokens() {
+    let args = vec![String::from("file.txt"), String::from("--=value"), String::from("-")];
+    let parsed = parse_arguments_lenient(args);
+    assert_eq!(parsed.positional(0), Some("file.txt"));
+}
+
+#[test]
+fn test_parser_fills_in_declared_default() {
+    let parser = Parser::new().arg(ArgSpec::new("threads").short('t').takes_value(true).default("1"));
+    match parser.parse(vec![]).unwrap() {
+        ParseOutcome::Matched { args, .. } => assert_eq!(args.variable("threads"), Some("1")),
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+}
+
+#[test]
+fn test_parser_errors_on_missing_required_option() {
+    let parser = Parser::new().arg(ArgSpec::new("output").short('o').takes_value(true).required(true));
+    let err = parser.parse(vec![]).unwrap_err();
+    assert_eq!(err, ParseError::MissingRequiredOption(String::from("output")));
+}
+
+#[test]
+fn test_parser_errors_on_unknown_option() {
+    let parser = Parser::new().arg(ArgSpec::new("output").short('o').takes_value(true));
+    let err = parser.parse(vec![String::from("--bogus")]).unwrap_err();
+    assert_eq!(err, ParseError::UnknownOption(String::from("bogus")));
+}
+
+#[test]
+fn test_parse_arguments_groups_bare_short_flags() {
+    let parsed = parse_arguments(vec![String::from("-rf")]).unwrap();
+    assert!(parsed.has_flag('r'));
+    assert!(parsed.has_flag('f'));
+    assert_eq!(parsed.variable("r"), None);
+    assert_eq!(parsed.variable("f"), None);
+}
+
+#[test]
+fn test_parser_groups_bare_short_flags_when_none_take_a_value() {
+    let parser = Parser::new()
+        .arg(ArgSpec::new("recursive").short('r'))
+        .arg(ArgSpec::new("force").short('f'));
+    match parser.parse(vec![String::from("-rf")]).unwrap() {
+        ParseOutcome::Matched { args, .. } => {
+            assert!(args.has_flag('r'));
+            assert!(args.has_flag('f'));
+        }
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+}
+
+#[test]
+fn test_parser_only_consumes_a_value_for_the_flag_declared_takes_value() {
+    let parser = Parser::new()
+        .arg(ArgSpec::new("recursive").short('r'))
+        .arg(ArgSpec::new("output").short('o').takes_value(true));
+    match parser.parse(vec![String::from("-ro"), String::from("out.txt")]).unwrap() {
+        ParseOutcome::Matched { args, .. } => {
+            assert!(args.has_flag('r'));
+            assert_eq!(args.variable("output"), Some("out.txt"));
+        }
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+}
+
+#[test]
+fn test_parse_arguments_stops_at_delimiter() {
+    let parsed = parse_arguments(vec![String::from("--"), String::from("-f"), String::from("--quiet")]).unwrap();
+    assert_eq!(parsed.positional(0), Some("-f"));
+    assert_eq!(parsed.positional(1), Some("--quiet"));
+    assert!(!parsed.has_flag('f'));
+    assert!(!parsed.has_option("quiet"));
+}
+
+fn test_dispatcher() -> SubcommandParser {
+    return SubcommandParser::new().name("myprog").subcommand(Subcommand::new(
+        "commit",
+        Parser::new()
+            .arg(ArgSpec::new("amend"))
+            .arg(ArgSpec::new("message").short('m').takes_value(true)),
+    ));
+}
+
+#[test]
+fn test_subcommand_parser_dispatches_to_matching_subcommand() {
+    let dispatcher = test_dispatcher();
+    match dispatcher
+        .parse(vec![String::from("commit"), String::from("--amend"), String::from("-m"), String::from("fix typo")])
+        .unwrap()
+    {
+        SubcommandOutcome::Matched { name, args, sources } => {
+            assert_eq!(name, "commit");
+            assert!(args.has_option("amend"));
+            assert_eq!(args.variable("message"), Some("fix typo"));
+            assert_eq!(sources.get("message"), Some(&OptionSource::CommandLine));
+        }
+        SubcommandOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+}
+
+#[test]
+fn test_subcommand_parser_errors_on_missing_subcommand() {
+    let err = test_dispatcher().parse(vec![]).unwrap_err();
+    assert_eq!(err, ParseError::MissingSubcommand);
+}
+
+#[test]
+fn test_subcommand_parser_errors_on_unknown_subcommand() {
+    let err = test_dispatcher().parse(vec![String::from("bogus")]).unwrap_err();
+    assert_eq!(err, ParseError::UnknownSubcommand(String::from("bogus")));
+}
+
+#[test]
+fn test_subcommand_parser_help_sub_dash_dash_help_is_qualified() {
+    match test_dispatcher().parse(vec![String::from("commit"), String::from("--help")]).unwrap() {
+        SubcommandOutcome::HelpRequested(text) => assert!(text.starts_with("myprog commit\n")),
+        SubcommandOutcome::Matched { .. } => panic!("expected help"),
+    }
+}
+
+#[test]
+fn test_subcommand_parser_help_sub_is_qualified() {
+    match test_dispatcher().parse(vec![String::from("help"), String::from("commit")]).unwrap() {
+        SubcommandOutcome::HelpRequested(text) => assert!(text.starts_with("myprog commit\n")),
+        SubcommandOutcome::Matched { .. } => panic!("expected help"),
+    }
+}
+
+#[test]
+fn test_subcommand_parser_help_unknown_subcommand_errors() {
+    let err = test_dispatcher().parse(vec![String::from("help"), String::from("bogus")]).unwrap_err();
+    assert_eq!(err, ParseError::UnknownSubcommand(String::from("bogus")));
+}
+
+#[test]
+fn test_parser_option_source_precedence_command_line_over_env_over_default() {
+    std::env::remove_var("SIMPLE_ARG_PARSER_TEST_LEVEL");
+    let parser = Parser::new().arg(
+        ArgSpec::new("level")
+            .takes_value(true)
+            .env("SIMPLE_ARG_PARSER_TEST_LEVEL")
+            .default("quiet"),
+    );
+
+    match parser.parse(vec![]).unwrap() {
+        ParseOutcome::Matched { args, sources } => {
+            assert_eq!(args.variable("level"), Some("quiet"));
+            assert_eq!(sources.get("level"), Some(&OptionSource::Default));
+        }
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+
+    std::env::set_var("SIMPLE_ARG_PARSER_TEST_LEVEL", "debug");
+    match parser.parse(vec![]).unwrap() {
+        ParseOutcome::Matched { args, sources } => {
+            assert_eq!(args.variable("level"), Some("debug"));
+            assert_eq!(sources.get("level"), Some(&OptionSource::Environment));
+        }
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+
+    match parser.parse(vec![String::from("--level=trace")]).unwrap() {
+        ParseOutcome::Matched { args, sources } => {
+            assert_eq!(args.variable("level"), Some("trace"));
+            assert_eq!(sources.get("level"), Some(&OptionSource::CommandLine));
+        }
+        ParseOutcome::HelpRequested(_) => panic!("did not expect help"),
+    }
+
+    std::env::remove_var("SIMPLE_ARG_PARSER_TEST_LEVEL");
+}